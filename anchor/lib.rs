@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use static_assertions::const_assert_eq;
+
+/// Fixed evaluator capacity of a single [`ProjectBoard`].
+pub const BOARD_CAPACITY: usize = 32;
 
 declare_id!("61YS7i32Y1oTRiMVsPay2Bgbx3ihsBoTKtWk38hRp8GW");
 
@@ -6,6 +11,108 @@ declare_id!("61YS7i32Y1oTRiMVsPay2Bgbx3ihsBoTKtWk38hRp8GW");
 pub mod agentpulse_program {
     use super::*;
 
+    pub fn create_registrar(ctx: Context<CreateRegistrar>, realm: u32) -> Result<()> {
+        let registrar = &mut ctx.accounts.registrar;
+        registrar.authority = ctx.accounts.authority.key();
+        registrar.community_mint = ctx.accounts.community_mint.key();
+        registrar.realm = realm;
+        registrar.bump = ctx.bumps.registrar;
+
+        msg!("AgentPulse: Registrar created for realm {}", realm);
+        Ok(())
+    }
+
+    pub fn create_voter(ctx: Context<CreateVoter>) -> Result<()> {
+        let voter = &mut ctx.accounts.voter;
+        voter.registrar = ctx.accounts.registrar.key();
+        voter.authority = ctx.accounts.authority.key();
+        voter.amount_locked = 0;
+        voter.lockup_start_ts = 0;
+        voter.lockup_duration_secs = 0;
+        voter.lockup_kind = LockupKind::Cliff as u8;
+        voter.bump = ctx.bumps.voter;
+
+        msg!("AgentPulse: Voter registered for {}", voter.authority);
+        Ok(())
+    }
+
+    pub fn deposit(
+        ctx: Context<Deposit>,
+        amount: u64,
+        lockup_duration_secs: u64,
+        lockup_kind: u8,
+    ) -> Result<()> {
+        // Reject unknown kinds up front; otherwise a bad value would brick the
+        // voter, since every later `voting_power` call would error on it.
+        LockupKind::try_from(lockup_kind)?;
+
+        // Monotonic lockup: a new deposit may extend the lock but never shorten
+        // it, so a voter can't snapshot near-max weight and then unlock early.
+        let now = Clock::get()?.unix_timestamp;
+        let new_expiry = now.saturating_add(lockup_duration_secs as i64);
+        let current_expiry = {
+            let voter = &ctx.accounts.voter;
+            voter
+                .lockup_start_ts
+                .saturating_add(voter.lockup_duration_secs as i64)
+        };
+        require!(new_expiry >= current_expiry, AgentPulseError::LockupTooShort);
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.depositor_token.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        let voter = &mut ctx.accounts.voter;
+        voter.amount_locked = voter
+            .amount_locked
+            .checked_add(amount)
+            .ok_or(AgentPulseError::Overflow)?;
+        voter.lockup_start_ts = now;
+        voter.lockup_duration_secs = lockup_duration_secs;
+        voter.lockup_kind = lockup_kind;
+
+        msg!("AgentPulse: Deposited {} - locked stake now {}", amount, voter.amount_locked);
+        Ok(())
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let voter = &ctx.accounts.voter;
+        let now = Clock::get()?.unix_timestamp;
+        let expiry = voter
+            .lockup_start_ts
+            .saturating_add(voter.lockup_duration_secs as i64);
+        require!(now >= expiry, AgentPulseError::LockupNotExpired);
+        require!(amount <= voter.amount_locked, AgentPulseError::InsufficientStake);
+
+        let registrar = &ctx.accounts.registrar;
+        let realm = registrar.realm.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[b"registrar", &realm, &[registrar.bump]]];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.depositor_token.to_account_info(),
+            authority: registrar.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)?;
+
+        let voter = &mut ctx.accounts.voter;
+        voter.amount_locked = voter
+            .amount_locked
+            .checked_sub(amount)
+            .ok_or(AgentPulseError::Overflow)?;
+
+        msg!("AgentPulse: Withdrew {} - locked stake now {}", amount, voter.amount_locked);
+        Ok(())
+    }
+
     pub fn record_evaluation(
         ctx: Context<RecordEvaluation>,
         project_id: u32,
@@ -14,18 +121,49 @@ pub mod agentpulse_program {
         confidence: u16,
         reasoning_hash: [u8; 32],
     ) -> Result<()> {
+        let weight = ctx.accounts.voter.voting_power(Clock::get()?.unix_timestamp)?;
+
+        let now = Clock::get()?.unix_timestamp;
         let eval = &mut ctx.accounts.evaluation;
+        let is_new = eval.timestamp == 0;
+        // Freshness guard: an overwrite must not move the record back in time.
+        require!(now >= eval.timestamp, AgentPulseError::StaleTimestamp);
+        // Diff against any prior contribution so repeated evaluations from the
+        // same wallet don't double-count in the aggregator. On a fresh `init`
+        // these are zero and the subtraction is a no-op.
+        let old_score = eval.score;
+        let old_weight = eval.weight;
+
         eval.authority = ctx.accounts.authority.key();
+        eval.registrar = ctx.accounts.registrar.key();
         eval.project_id = project_id;
         eval.project_name = project_name[..project_name.len().min(64)].to_string();
         eval.score = score;
         eval.confidence = confidence;
+        eval.weight = weight;
         eval.reasoning_hash = reasoning_hash;
-        eval.timestamp = Clock::get()?.unix_timestamp;
+        eval.timestamp = now;
         eval.bump = ctx.bumps.evaluation;
-        
-        msg!("AgentPulse: Evaluated project {} - score {}/100, confidence {}%", 
+
+        let consensus = &mut ctx.accounts.consensus;
+        consensus.registrar = ctx.accounts.registrar.key();
+        consensus.project_id = project_id;
+        consensus.bump = ctx.bumps.consensus;
+        consensus.apply_evaluation(old_score, old_weight, score, weight, is_new)?;
+
+        msg!("AgentPulse: Evaluated project {} - score {}/100, confidence {}%",
             eval.project_name, score, confidence);
+
+        emit!(EvaluationRecorded {
+            evaluation: eval.key(),
+            authority: eval.authority,
+            project_id,
+            score,
+            confidence,
+            weight,
+            reasoning_hash,
+            timestamp: eval.timestamp,
+        });
         Ok(())
     }
 
@@ -35,30 +173,346 @@ pub mod agentpulse_program {
         vote_type: u8,
         reasoning_hash: [u8; 32],
     ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let weight = ctx.accounts.voter.voting_power(now)?;
+
         let vote = &mut ctx.accounts.vote_record;
+        require!(now >= vote.timestamp, AgentPulseError::StaleTimestamp);
+        let old_vote_type = vote.vote_type;
+        let old_weight = vote.weight;
+
         vote.authority = ctx.accounts.authority.key();
+        vote.registrar = ctx.accounts.registrar.key();
         vote.project_id = project_id;
         vote.vote_type = vote_type;
+        vote.weight = weight;
         vote.reasoning_hash = reasoning_hash;
-        vote.timestamp = Clock::get()?.unix_timestamp;
+        vote.timestamp = now;
         vote.bump = ctx.bumps.vote_record;
-        
-        msg!("AgentPulse: Voted on project {} - type {}", project_id, vote_type);
+
+        let consensus = &mut ctx.accounts.consensus;
+        consensus.registrar = ctx.accounts.registrar.key();
+        consensus.project_id = project_id;
+        consensus.bump = ctx.bumps.consensus;
+        consensus.apply_vote(old_vote_type, old_weight, vote_type, weight)?;
+
+        msg!("AgentPulse: Voted on project {} - type {}, weight {}", project_id, vote_type, weight);
+
+        emit!(VoteRecorded {
+            vote_record: vote.key(),
+            authority: vote.authority,
+            project_id,
+            vote_type,
+            weight,
+            reasoning_hash,
+            timestamp: vote.timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn init_project_board(ctx: Context<InitProjectBoard>, project_id: u32) -> Result<()> {
+        let mut board = ctx.accounts.board.load_init()?;
+        board.project_id = project_id;
+        board.slot_count = 0;
+        board.bump = ctx.bumps.board;
+
+        msg!("AgentPulse: Opened board for project {}", project_id);
+        Ok(())
+    }
+
+    pub fn record_slot(
+        ctx: Context<RecordSlot>,
+        _project_id: u32,
+        score: u16,
+        confidence: u16,
+        reasoning_hash: [u8; 32],
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let weight = ctx.accounts.voter.voting_power(Clock::get()?.unix_timestamp)?;
+        let mut board = ctx.accounts.board.load_mut()?;
+
+        // Reuse this wallet's existing slot when present, otherwise claim the
+        // next free one. Either way the write below is an O(1) in-place update.
+        let used = board.slot_count as usize;
+        let idx = match board.slots[..used]
+            .iter()
+            .position(|s| s.authority == authority)
+        {
+            Some(existing) => existing,
+            None => {
+                require!(used < BOARD_CAPACITY, AgentPulseError::BoardFull);
+                board.slot_count += 1;
+                used
+            }
+        };
+
+        let slot = &mut board.slots[idx];
+        slot.authority = authority;
+        slot.score = score;
+        slot.confidence = confidence;
+        slot.weight = weight;
+        slot.reasoning_hash = reasoning_hash;
+
+        msg!("AgentPulse: Board slot {} set for project {}", idx, _project_id);
+        Ok(())
+    }
+
+    pub fn revoke_evaluation(ctx: Context<RevokeEvaluation>) -> Result<()> {
+        let eval = &ctx.accounts.evaluation;
+        ctx.accounts
+            .consensus
+            .remove_evaluation(eval.score, eval.weight)?;
+
+        msg!("AgentPulse: Revoked evaluation for project {}", eval.project_id);
         Ok(())
     }
+
+    pub fn revoke_vote(ctx: Context<RevokeVote>) -> Result<()> {
+        let vote = &ctx.accounts.vote_record;
+        ctx.accounts
+            .consensus
+            .remove_vote(vote.vote_type, vote.weight)?;
+
+        msg!("AgentPulse: Revoked vote for project {}", vote.project_id);
+        Ok(())
+    }
+}
+
+#[event]
+pub struct EvaluationRecorded {
+    pub evaluation: Pubkey,
+    pub authority: Pubkey,
+    pub project_id: u32,
+    pub score: u16,
+    pub confidence: u16,
+    pub weight: u64,
+    pub reasoning_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteRecorded {
+    pub vote_record: Pubkey,
+    pub authority: Pubkey,
+    pub project_id: u32,
+    pub vote_type: u8,
+    pub weight: u64,
+    pub reasoning_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(realm: u32)]
+pub struct CreateRegistrar<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Registrar::INIT_SPACE,
+        seeds = [b"registrar", &realm.to_le_bytes()],
+        bump
+    )]
+    pub registrar: Account<'info, Registrar>,
+    pub community_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = authority,
+        token::mint = community_mint,
+        token::authority = registrar,
+        seeds = [registrar.key().as_ref(), b"vault"],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateVoter<'info> {
+    pub registrar: Account<'info, Registrar>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Voter::INIT_SPACE,
+        seeds = [registrar.key().as_ref(), b"voter", authority.key().as_ref()],
+        bump
+    )]
+    pub voter: Account<'info, Voter>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    pub registrar: Account<'info, Registrar>,
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter", authority.key().as_ref()],
+        bump = voter.bump,
+        has_one = authority,
+        has_one = registrar,
+    )]
+    pub voter: Account<'info, Voter>,
+    #[account(
+        mut,
+        token::mint = registrar.community_mint,
+    )]
+    pub depositor_token: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = registrar.community_mint,
+        token::authority = registrar,
+        seeds = [registrar.key().as_ref(), b"vault"],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub registrar: Account<'info, Registrar>,
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter", authority.key().as_ref()],
+        bump = voter.bump,
+        has_one = authority,
+        has_one = registrar,
+    )]
+    pub voter: Account<'info, Voter>,
+    #[account(
+        mut,
+        token::mint = registrar.community_mint,
+    )]
+    pub depositor_token: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = registrar.community_mint,
+        token::authority = registrar,
+        seeds = [registrar.key().as_ref(), b"vault"],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
 #[instruction(project_id: u32)]
-pub struct RecordEvaluation<'info> {
+pub struct InitProjectBoard<'info> {
     #[account(
         init,
         payer = authority,
+        space = 8 + std::mem::size_of::<ProjectBoard>(),
+        seeds = [b"board", &project_id.to_le_bytes()],
+        bump
+    )]
+    pub board: AccountLoader<'info, ProjectBoard>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: u32)]
+pub struct RecordSlot<'info> {
+    #[account(
+        mut,
+        seeds = [b"board", &project_id.to_le_bytes()],
+        bump = board.load()?.bump,
+    )]
+    pub board: AccountLoader<'info, ProjectBoard>,
+    #[account(
+        seeds = [registrar.key().as_ref(), b"voter", authority.key().as_ref()],
+        bump = voter.bump,
+        has_one = authority,
+        has_one = registrar,
+    )]
+    pub voter: Account<'info, Voter>,
+    pub registrar: Account<'info, Registrar>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: u32)]
+pub struct RevokeEvaluation<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"eval", authority.key().as_ref(), &project_id.to_le_bytes()],
+        bump = evaluation.bump,
+        has_one = authority,
+        has_one = registrar,
+    )]
+    pub evaluation: Account<'info, EvaluationRecord>,
+    pub registrar: Account<'info, Registrar>,
+    #[account(
+        mut,
+        seeds = [b"consensus", registrar.key().as_ref(), &project_id.to_le_bytes()],
+        bump = consensus.bump,
+        has_one = registrar,
+    )]
+    pub consensus: Account<'info, ProjectConsensus>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: u32)]
+pub struct RevokeVote<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"vote", authority.key().as_ref(), &project_id.to_le_bytes()],
+        bump = vote_record.bump,
+        has_one = authority,
+        has_one = registrar,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    pub registrar: Account<'info, Registrar>,
+    #[account(
+        mut,
+        seeds = [b"consensus", registrar.key().as_ref(), &project_id.to_le_bytes()],
+        bump = consensus.bump,
+        has_one = registrar,
+    )]
+    pub consensus: Account<'info, ProjectConsensus>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: u32)]
+pub struct RecordEvaluation<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
         space = 8 + EvaluationRecord::INIT_SPACE,
         seeds = [b"eval", authority.key().as_ref(), &project_id.to_le_bytes()],
         bump
     )]
     pub evaluation: Account<'info, EvaluationRecord>,
+    #[account(
+        seeds = [registrar.key().as_ref(), b"voter", authority.key().as_ref()],
+        bump = voter.bump,
+        has_one = authority,
+        has_one = registrar,
+    )]
+    pub voter: Account<'info, Voter>,
+    pub registrar: Account<'info, Registrar>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProjectConsensus::INIT_SPACE,
+        seeds = [b"consensus", registrar.key().as_ref(), &project_id.to_le_bytes()],
+        bump
+    )]
+    pub consensus: Account<'info, ProjectConsensus>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -68,27 +522,267 @@ pub struct RecordEvaluation<'info> {
 #[instruction(project_id: u32)]
 pub struct RecordVote<'info> {
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
         space = 8 + VoteRecord::INIT_SPACE,
         seeds = [b"vote", authority.key().as_ref(), &project_id.to_le_bytes()],
         bump
     )]
     pub vote_record: Account<'info, VoteRecord>,
+    #[account(
+        seeds = [registrar.key().as_ref(), b"voter", authority.key().as_ref()],
+        bump = voter.bump,
+        has_one = authority,
+        has_one = registrar,
+    )]
+    pub voter: Account<'info, Voter>,
+    pub registrar: Account<'info, Registrar>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProjectConsensus::INIT_SPACE,
+        seeds = [b"consensus", registrar.key().as_ref(), &project_id.to_le_bytes()],
+        bump
+    )]
+    pub consensus: Account<'info, ProjectConsensus>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[account]
+#[derive(InitSpace)]
+pub struct Registrar {
+    pub authority: Pubkey,
+    pub community_mint: Pubkey,
+    pub realm: u32,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Voter {
+    pub registrar: Pubkey,
+    pub authority: Pubkey,
+    pub amount_locked: u64,
+    pub lockup_start_ts: i64,
+    pub lockup_duration_secs: u64,
+    pub lockup_kind: u8,
+    pub bump: u8,
+}
+
+impl Voter {
+    /// Upper bound on lockup influence: deposits locked for longer than this
+    /// earn no additional bonus. Chosen to match the ~7 year horizon used by
+    /// comparable vote-escrow designs.
+    pub const MAX_LOCKUP_SECS: u64 = 7 * 365 * 24 * 60 * 60;
+
+    /// Effective voting power at `now`: the deposited `amount_locked` plus a
+    /// lockup bonus proportional to the remaining locked time. A cliff lockup
+    /// keeps the full bonus until expiry; a linear lockup decays it toward zero.
+    pub fn voting_power(&self, now: i64) -> Result<u64> {
+        let expiry = self.lockup_start_ts.saturating_add(self.lockup_duration_secs as i64);
+        let remaining_secs = match LockupKind::try_from(self.lockup_kind)? {
+            LockupKind::Cliff => {
+                if now < expiry {
+                    self.lockup_duration_secs
+                } else {
+                    0
+                }
+            }
+            LockupKind::Linear => (expiry - now).max(0) as u64,
+        };
+        let remaining_secs = remaining_secs.min(Self::MAX_LOCKUP_SECS);
+
+        let amount = self.amount_locked as u128;
+        let bonus = amount
+            .checked_mul(remaining_secs as u128)
+            .ok_or(AgentPulseError::Overflow)?
+            / Self::MAX_LOCKUP_SECS as u128;
+        // `amount + bonus` can approach `2 * amount_locked`, which overflows
+        // `u64` for large-but-legitimate deposits. Saturate rather than error so
+        // a big stake caps its weight instead of being locked out of voting.
+        let total = amount.saturating_add(bonus).min(u64::MAX as u128);
+
+        Ok(total as u64)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LockupKind {
+    Cliff = 0,
+    Linear = 1,
+}
+
+impl TryFrom<u8> for LockupKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(LockupKind::Cliff),
+            1 => Ok(LockupKind::Linear),
+            _ => Err(AgentPulseError::InvalidLockupKind.into()),
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectConsensus {
+    pub registrar: Pubkey,
+    pub project_id: u32,
+    pub total_weight: u128,
+    pub weighted_score_sum: u128,
+    pub evaluation_count: u32,
+    pub upvote_weight: u128,
+    pub downvote_weight: u128,
+    pub bump: u8,
+}
+
+impl ProjectConsensus {
+    /// Fold an evaluation into the running tally, first backing out the voter's
+    /// previous contribution (`old_*`, zero when this is a brand-new record).
+    pub fn apply_evaluation(
+        &mut self,
+        old_score: u16,
+        old_weight: u64,
+        new_score: u16,
+        new_weight: u64,
+        is_new: bool,
+    ) -> Result<()> {
+        let old = (old_score as u128) * (old_weight as u128);
+        let new = (new_score as u128) * (new_weight as u128);
+        self.weighted_score_sum = self
+            .weighted_score_sum
+            .checked_sub(old)
+            .and_then(|s| s.checked_add(new))
+            .ok_or(AgentPulseError::Overflow)?;
+        self.total_weight = self
+            .total_weight
+            .checked_sub(old_weight as u128)
+            .and_then(|w| w.checked_add(new_weight as u128))
+            .ok_or(AgentPulseError::Overflow)?;
+        if is_new {
+            self.evaluation_count = self
+                .evaluation_count
+                .checked_add(1)
+                .ok_or(AgentPulseError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Fold a vote into the per-type tallies, moving the voter's old weight out
+    /// of its previous bucket before crediting the new one.
+    pub fn apply_vote(
+        &mut self,
+        old_vote_type: u8,
+        old_weight: u64,
+        new_vote_type: u8,
+        new_weight: u64,
+    ) -> Result<()> {
+        if old_weight != 0 {
+            let bucket = self.bucket_mut(old_vote_type);
+            *bucket = bucket
+                .checked_sub(old_weight as u128)
+                .ok_or(AgentPulseError::Overflow)?;
+        }
+        let bucket = self.bucket_mut(new_vote_type);
+        *bucket = bucket
+            .checked_add(new_weight as u128)
+            .ok_or(AgentPulseError::Overflow)?;
+        Ok(())
+    }
+
+    /// Back a revoked evaluation out of the tally, mirroring the zero-out on
+    /// `close`. The evaluator's slot in the count is released as well.
+    pub fn remove_evaluation(&mut self, score: u16, weight: u64) -> Result<()> {
+        let contribution = (score as u128) * (weight as u128);
+        self.weighted_score_sum = self
+            .weighted_score_sum
+            .checked_sub(contribution)
+            .ok_or(AgentPulseError::Overflow)?;
+        self.total_weight = self
+            .total_weight
+            .checked_sub(weight as u128)
+            .ok_or(AgentPulseError::Overflow)?;
+        self.evaluation_count = self
+            .evaluation_count
+            .checked_sub(1)
+            .ok_or(AgentPulseError::Overflow)?;
+        Ok(())
+    }
+
+    /// Back a revoked vote out of its per-type bucket.
+    pub fn remove_vote(&mut self, vote_type: u8, weight: u64) -> Result<()> {
+        let bucket = self.bucket_mut(vote_type);
+        *bucket = bucket
+            .checked_sub(weight as u128)
+            .ok_or(AgentPulseError::Overflow)?;
+        Ok(())
+    }
+
+    /// Weighted mean score across all evaluators, or `0` before any weight has
+    /// accrued. Exposed so a frontend reads one account for the crowd verdict.
+    pub fn consensus_score(&self) -> u128 {
+        if self.total_weight == 0 {
+            0
+        } else {
+            self.weighted_score_sum / self.total_weight
+        }
+    }
+
+    /// Upvotes are `vote_type != 0`; anything else counts as a downvote.
+    fn bucket_mut(&mut self, vote_type: u8) -> &mut u128 {
+        if vote_type != 0 {
+            &mut self.upvote_weight
+        } else {
+            &mut self.downvote_weight
+        }
+    }
+}
+
+/// One evaluator's packed contribution inside a [`ProjectBoard`]. The field
+/// order and trailing padding keep the `repr(C)` layout 8-byte aligned so the
+/// slot array never triggers an unaligned-reference panic.
+#[zero_copy]
+#[repr(C)]
+pub struct EvaluatorSlot {
+    pub authority: Pubkey,
+    pub weight: u64,
+    pub reasoning_hash: [u8; 32],
+    pub score: u16,
+    pub confidence: u16,
+    pub _padding: [u8; 4],
+}
+
+const_assert_eq!(std::mem::size_of::<EvaluatorSlot>(), 80);
+
+/// Zero-copy board batching up to [`BOARD_CAPACITY`] evaluator slots into a
+/// single account, so many evaluators share one allocation instead of a
+/// borsh-serialized record each.
+#[account(zero_copy)]
+#[repr(C)]
+pub struct ProjectBoard {
+    pub project_id: u32,
+    pub slot_count: u32,
+    pub bump: u8,
+    pub _padding: [u8; 7],
+    pub slots: [EvaluatorSlot; BOARD_CAPACITY],
+}
+
+const_assert_eq!(std::mem::size_of::<ProjectBoard>(), 16 + 80 * BOARD_CAPACITY);
+
 #[account]
 #[derive(InitSpace)]
 pub struct EvaluationRecord {
     pub authority: Pubkey,
+    pub registrar: Pubkey,
     pub project_id: u32,
     #[max_len(64)]
     pub project_name: String,
     pub score: u16,
     pub confidence: u16,
+    pub weight: u64,
     pub reasoning_hash: [u8; 32],
     pub timestamp: i64,
     pub bump: u8,
@@ -98,9 +792,29 @@ pub struct EvaluationRecord {
 #[derive(InitSpace)]
 pub struct VoteRecord {
     pub authority: Pubkey,
+    pub registrar: Pubkey,
     pub project_id: u32,
     pub vote_type: u8,
+    pub weight: u64,
     pub reasoning_hash: [u8; 32],
     pub timestamp: i64,
     pub bump: u8,
 }
+
+#[error_code]
+pub enum AgentPulseError {
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Unknown lockup kind")]
+    InvalidLockupKind,
+    #[msg("Record timestamp would move backwards")]
+    StaleTimestamp,
+    #[msg("Project board is at capacity")]
+    BoardFull,
+    #[msg("Lockup has not expired yet")]
+    LockupNotExpired,
+    #[msg("Withdrawal exceeds locked stake")]
+    InsufficientStake,
+    #[msg("Deposit would shorten the existing lockup")]
+    LockupTooShort,
+}